@@ -3,13 +3,18 @@ use feroxbuster::{
     banner,
     config::{CONFIGURATION, PROGRESS_PRINTER},
     heuristics, logger, reporter,
-    scanner::{scan_url, PAUSE_SCAN},
+    scanner::{
+        scan_url, ACTIVE_SCANS, CANCEL_SCAN, CURRENT_DEPTH, PAUSE_SCAN, REQUESTS_SENT,
+        RUNTIME_VERBOSITY, SCAN_LIMIT,
+    },
     utils::{ferox_print, get_current_depth, module_colorizer, status_colorizer},
     FeroxResponse, FeroxResult, SLEEP_DURATION, VERSION,
 };
 use futures::StreamExt;
+use rand::seq::SliceRandom;
+use serde::Deserialize;
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     fs::File,
     io::{stderr, BufRead, BufReader},
     process,
@@ -17,31 +22,99 @@ use std::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
-    time::Duration,
+    time::{Duration, Instant},
+};
+use tokio::{
+    io,
+    io::{AsyncBufReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    process::Command,
+    sync::{
+        mpsc::{self, UnboundedReceiver, UnboundedSender},
+        Semaphore,
+    },
 };
-use tokio::{io, sync::mpsc::UnboundedSender};
 use tokio_util::codec::{FramedRead, LinesCodec};
 
 /// Atomic boolean flag, used to determine whether or not the terminal input handler should exit
 pub static SCAN_COMPLETE: AtomicBool = AtomicBool::new(false);
 
-/// Handles specific key events triggered by the user over stdin
+/// Handles key events triggered by the user over stdin, acting as a live control surface
+///
+/// The terminal is placed in raw mode so individual key presses arrive immediately. Recognized
+/// keys steer the running scan through the `scanner`'s shared atomics — analogous to how `Enter`
+/// toggles [`PAUSE_SCAN`] — giving operators the same in-scan steering a TUI front-end would
+/// provide:
+///
+/// * `Enter` — pause/resume the whole scan
+/// * `c`     — cancel just the directory scan that is currently active
+/// * `+`/`-` — raise/lower the concurrency limit
+/// * `v`     — bump verbosity
+/// * `q`     — restore the terminal and quit the process
+///
+/// Raw mode disables `ISIG`, so `Ctrl-C` will not interrupt the process while the handler holds
+/// it; `q` is provided as the explicit quit that first restores the terminal.
 fn terminal_input_handler() {
     log::trace!("enter: terminal_input_handler");
 
+    // raw mode lets us decode single key presses without waiting for Enter; if it fails we carry
+    // on in cooked mode with a reduced set of usable controls
+    let raw_mode = crossterm::terminal::enable_raw_mode().is_ok();
+
+    // bookkeeping for the live status region: we refresh roughly once a second even when no key
+    // is pressed, deriving the request rate from the delta in REQUESTS_SENT since the last redraw
+    let mut last_render = Instant::now();
+    let mut last_requests = REQUESTS_SENT.load(Ordering::Relaxed);
+    let mut last_rate = 0.0_f64;
+
     loop {
         if event::poll(Duration::from_millis(SLEEP_DURATION)).unwrap_or(false) {
             // It's guaranteed that the `read()` won't block when the `poll()`
             // function returns `true`
 
-            if let Ok(key_pressed) = event::read() {
-                if key_pressed == Event::Key(KeyCode::Enter.into()) {
-                    // if the user presses Enter, toggle the value stored in PAUSE_SCAN
-                    // ignore any other keys
-                    let current = PAUSE_SCAN.load(Ordering::Acquire);
-
-                    PAUSE_SCAN.store(!current, Ordering::Release);
+            if let Ok(Event::Key(key_event)) = event::read() {
+                match key_event.code {
+                    KeyCode::Enter => {
+                        // toggle the value stored in PAUSE_SCAN
+                        let current = PAUSE_SCAN.load(Ordering::Acquire);
+                        PAUSE_SCAN.store(!current, Ordering::Release);
+                    }
+                    KeyCode::Char('c') => {
+                        // signal the scanner to abandon the active directory scan
+                        CANCEL_SCAN.store(true, Ordering::Release);
+                        log::info!("operator cancelled the active directory scan");
+                    }
+                    KeyCode::Char('+') | KeyCode::Char('=') => {
+                        let new = SCAN_LIMIT.fetch_add(1, Ordering::AcqRel) + 1;
+                        log::info!("concurrency limit raised to {}", new);
+                    }
+                    KeyCode::Char('-') | KeyCode::Char('_') => {
+                        // never drop below a single concurrent request
+                        let current = SCAN_LIMIT.load(Ordering::Acquire);
+                        if current > 1 {
+                            SCAN_LIMIT.store(current - 1, Ordering::Release);
+                            log::info!("concurrency limit lowered to {}", current - 1);
+                        }
+                    }
+                    KeyCode::Char('v') => {
+                        let new = RUNTIME_VERBOSITY.fetch_add(1, Ordering::AcqRel) + 1;
+                        log::info!("verbosity bumped to {}", new);
+                    }
+                    KeyCode::Char('q') => {
+                        // explicit quit: restore the terminal before leaving, since raw mode has
+                        // disabled the usual Ctrl-C interrupt for the life of the handler
+                        if raw_mode {
+                            let _ = crossterm::terminal::disable_raw_mode();
+                        }
+                        log::info!("operator requested quit");
+                        process::exit(0);
+                    }
+                    // any other key is ignored
+                    _ => continue,
                 }
+
+                // re-render immediately so the operator sees the effect of their key press
+                render_status(last_rate);
             }
         } else {
             // Timeout expired and no `Event` is available; use the timeout to check SCAN_COMPLETE
@@ -50,10 +123,53 @@ fn terminal_input_handler() {
                 break;
             }
         }
+
+        // refresh the live status region on a ~1s cadence regardless of key activity
+        let elapsed = last_render.elapsed();
+        if elapsed >= Duration::from_secs(1) {
+            let now_requests = REQUESTS_SENT.load(Ordering::Relaxed);
+            last_rate = now_requests.saturating_sub(last_requests) as f64 / elapsed.as_secs_f64();
+            last_requests = now_requests;
+            last_render = Instant::now();
+            render_status(last_rate);
+        }
+    }
+
+    if raw_mode {
+        // restore the terminal on the way out; nothing useful to do if tear-down fails
+        let _ = crossterm::terminal::disable_raw_mode();
     }
     log::trace!("exit: terminal_input_handler");
 }
 
+/// Render a compact live status region summarizing the current scan state
+///
+/// The caller refreshes this on a ~1s cadence (and on every key press), passing the request
+/// `rate` measured since the last redraw. The line surfaces the operator-adjustable knobs (pause
+/// state, concurrency limit, verbosity) alongside the live scan metrics the request calls for:
+/// the number of active per-target/directory scans, the current recursion depth, and the request
+/// rate. Detailed per-target progress bars continue to be drawn by the `MultiProgress`.
+fn render_status(rate: f64) {
+    let state = if PAUSE_SCAN.load(Ordering::Acquire) {
+        "paused"
+    } else {
+        "running"
+    };
+
+    let msg = format!(
+        "{} {} | active {} | depth {} | {:.0} req/s | concurrency {} | verbosity {}",
+        module_colorizer("control-panel"),
+        status_colorizer(state),
+        ACTIVE_SCANS.load(Ordering::Acquire),
+        CURRENT_DEPTH.load(Ordering::Acquire),
+        rate,
+        SCAN_LIMIT.load(Ordering::Acquire),
+        RUNTIME_VERBOSITY.load(Ordering::Acquire),
+    );
+
+    ferox_print(&msg, &PROGRESS_PRINTER);
+}
+
 /// Create a HashSet of Strings from the given wordlist then stores it inside an Arc
 fn get_unique_words_from_wordlist(path: &str) -> FeroxResult<Arc<HashSet<String>>> {
     log::trace!("enter: get_unique_words_from_wordlist({})", path);
@@ -88,6 +204,11 @@ fn get_unique_words_from_wordlist(path: &str) -> FeroxResult<Arc<HashSet<String>
         words.insert(result);
     }
 
+    if CONFIGURATION.markov > 0 {
+        // grow the loaded list with synthetic candidates modeled on its own entries
+        expand_with_markov(&mut words, CONFIGURATION.markov_order, CONFIGURATION.markov);
+    }
+
     log::trace!(
         "exit: get_unique_words_from_wordlist -> Arc<wordlist[{} words...]>",
         words.len()
@@ -96,13 +217,103 @@ fn get_unique_words_from_wordlist(path: &str) -> FeroxResult<Arc<HashSet<String>
     Ok(Arc::new(words))
 }
 
+/// Upper bound on the length of a single Markov-generated candidate; keeps runaway
+/// chains from producing pathologically long words when the end token is rarely sampled
+const MARKOV_MAX_WORD_LEN: usize = 64;
+
+/// Train a character-level order-`k` Markov model on the already-loaded `words` and insert
+/// up to `count` fresh synthetic candidates back into the set. Sampling stops after `16 * count`
+/// attempts, so a model that keeps reproducing existing words yields fewer than `count`.
+///
+/// Two tables are built from the seed corpus: a map from each `k`-character prefix to the
+/// distribution of characters that follow it (with `None` standing in for the end-of-word
+/// token), and a weighted list of starting prefixes. A candidate is emitted by sampling a
+/// start prefix, then repeatedly sampling the next character conditioned on its trailing `k`
+/// characters until the end token fires or [`MARKOV_MAX_WORD_LEN`] is reached. Candidates
+/// already present in `words` are discarded rather than re-inserted.
+fn expand_with_markov(words: &mut HashSet<String>, order: usize, count: usize) {
+    log::trace!("enter: expand_with_markov(<{} words>, {}, {})", words.len(), order, count);
+
+    // an order of zero has no context to condition on, so it is treated as order 1
+    let order = order.max(1);
+
+    // prefix -> following characters (None marks the end of a word)
+    let mut transitions: HashMap<Vec<char>, Vec<Option<char>>> = HashMap::new();
+    // weighted table of word-starting prefixes (one entry per seed word)
+    let mut starts: Vec<Vec<char>> = Vec::new();
+
+    for word in words.iter() {
+        let chars: Vec<char> = word.chars().collect();
+        if chars.len() < order {
+            // too short to provide a full-width prefix; skip it
+            continue;
+        }
+
+        starts.push(chars[..order].to_vec());
+
+        for window_end in order..=chars.len() {
+            let prefix = chars[window_end - order..window_end].to_vec();
+            let next = chars.get(window_end).copied();
+            transitions.entry(prefix).or_default().push(next);
+        }
+    }
+
+    if starts.is_empty() {
+        // corpus too sparse for the requested order; nothing we can do
+        log::debug!("markov: no seed words long enough for order {}", order);
+        log::trace!("exit: expand_with_markov");
+        return;
+    }
+
+    let mut rng = rand::thread_rng();
+    let mut generated = 0usize;
+    // cap the number of sampling attempts so a near-degenerate model can't loop forever
+    let max_attempts = count.saturating_mul(16).max(count);
+
+    for _ in 0..max_attempts {
+        if generated >= count {
+            break;
+        }
+
+        let mut chars: Vec<char> = starts.choose(&mut rng).unwrap().clone();
+
+        while chars.len() < MARKOV_MAX_WORD_LEN {
+            let prefix = &chars[chars.len() - order..];
+            let followers = match transitions.get(prefix) {
+                Some(f) => f,
+                None => break,
+            };
+
+            match followers.choose(&mut rng) {
+                Some(Some(next)) => chars.push(*next),
+                // sampled the end-of-word token, or an empty distribution
+                _ => break,
+            }
+        }
+
+        let candidate: String = chars.into_iter().collect();
+        if candidate.is_empty() || words.contains(&candidate) {
+            continue;
+        }
+
+        words.insert(candidate);
+        generated += 1;
+    }
+
+    log::debug!("markov: generated {} synthetic candidates", generated);
+    log::trace!("exit: expand_with_markov");
+}
+
 /// Determine whether it's a single url scan or urls are coming from stdin, then scan as needed
+///
+/// Targets are pulled off `targets` as they're produced, so a `scan_url` task is spawned the
+/// moment each one arrives rather than after the whole feed has been collected.
 async fn scan(
-    targets: Vec<String>,
+    mut targets: UnboundedReceiver<String>,
     tx_term: UnboundedSender<FeroxResponse>,
     tx_file: UnboundedSender<String>,
 ) -> FeroxResult<()> {
-    log::trace!("enter: scan({:?}, {:?}, {:?})", targets, tx_term, tx_file);
+    log::trace!("enter: scan(<receiver>, {:?}, {:?})", tx_term, tx_file);
     // cloning an Arc is cheap (it's basically a pointer into the heap)
     // so that will allow for cheap/safe sharing of a single wordlist across multi-target scans
     // as well as additional directories found as part of recursion
@@ -122,12 +333,21 @@ async fn scan(
 
     let mut tasks = vec![];
 
-    for target in targets {
+    while let Some(target) = targets.recv().await {
         let word_clone = words.clone();
         let term_clone = tx_term.clone();
         let file_clone = tx_file.clone();
 
+        // run the connectivity test inside the per-target task so a single slow or unreachable
+        // target never blocks the recv loop from handling targets that arrive after it
         let task = tokio::spawn(async move {
+            let live = heuristics::connectivity_test(&[target]).await;
+            let target = match live.into_iter().next() {
+                // target didn't respond to the connectivity test; nothing to scan
+                Some(t) => t,
+                None => return,
+            };
+
             let base_depth = get_current_depth(&target);
             scan_url(&target, word_clone, base_depth, term_clone, file_clone).await;
         });
@@ -142,27 +362,289 @@ async fn scan(
     Ok(())
 }
 
-async fn get_targets() -> FeroxResult<Vec<String>> {
+/// A unit of work submitted by a connected client while running in server mode
+///
+/// Clients send one job per newline-delimited JSON object; every field beyond `target` is an
+/// optional override of the corresponding [`CONFIGURATION`] value for that job.
+#[derive(Debug, Deserialize)]
+struct ScanJob {
+    /// target url to scan
+    target: String,
+
+    /// override the wordlist used for this job; defaults to the server's loaded list
+    #[serde(default)]
+    wordlist: Option<String>,
+}
+
+/// Determine whether a connecting peer is permitted to enqueue work
+///
+/// An empty allowlist accepts every peer; otherwise the peer's address must be listed explicitly.
+fn peer_allowed(peer: &std::net::SocketAddr) -> bool {
+    if CONFIGURATION.allowed_peers.is_empty() {
+        return true;
+    }
+
+    let ip = peer.ip().to_string();
+    CONFIGURATION.allowed_peers.iter().any(|allowed| *allowed == ip)
+}
+
+/// Run feroxbuster as a long-lived scanning backend
+///
+/// A tokio listener accepts connections on `CONFIGURATION.listen_address`; each approved client
+/// submits jobs and has every [`FeroxResponse`] streamed back as newline-delimited JSON as it's
+/// found. The single loaded wordlist `Arc` is shared across every connection and every job that
+/// doesn't name its own wordlist, so a busy server never re-reads that list from disk; a job
+/// with a wordlist override loads its own list for that job.
+async fn run_server(words: Arc<HashSet<String>>) -> FeroxResult<()> {
+    log::trace!("enter: run_server(<{} words>)", words.len());
+
+    let listener = TcpListener::bind(&CONFIGURATION.listen_address).await?;
+    log::info!("server listening on {}", CONFIGURATION.listen_address);
+
+    loop {
+        let (stream, peer) = match listener.accept().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                // a transient accept failure (e.g. EMFILE) shouldn't bring down the daemon
+                log::error!("error accepting connection: {}", e);
+                continue;
+            }
+        };
+
+        if !peer_allowed(&peer) {
+            // acceptance filter: drop connections from peers that aren't on the allowlist
+            log::warn!("rejected connection from unapproved peer {}", peer);
+            continue;
+        }
+
+        // cloning the Arc is cheap and lets every connection reuse the one loaded wordlist
+        let words = words.clone();
+
+        tokio::spawn(async move {
+            if let Err(e) = handle_client(stream, words).await {
+                log::error!("error handling client {}: {}", peer, e);
+            }
+        });
+    }
+}
+
+/// Service a single connected client: read jobs and stream their findings back over the socket
+async fn handle_client(stream: TcpStream, words: Arc<HashSet<String>>) -> FeroxResult<()> {
+    log::trace!("enter: handle_client(<stream>, <{} words>)", words.len());
+
+    let (read_half, mut write_half) = stream.into_split();
+    let mut lines = io::BufReader::new(read_half).lines();
+
+    // each line is one job; run them sequentially so results interleave predictably on the wire
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let job: ScanJob = match serde_json::from_str(&line) {
+            Ok(j) => j,
+            Err(e) => {
+                log::error!("could not parse job {:?}: {}", line, e);
+                continue;
+            }
+        };
+
+        // honor a per-job wordlist override, otherwise reuse the server's shared list; the
+        // synchronous file read/hash is offloaded with spawn_blocking so it doesn't stall a
+        // runtime worker. A failed override only fails this job, like the malformed-JSON path.
+        let words = match job.wordlist.clone() {
+            Some(path) => {
+                let loaded =
+                    tokio::task::spawn_blocking(move || get_unique_words_from_wordlist(&path))
+                        .await;
+                match loaded {
+                    Ok(Ok(w)) => w,
+                    Ok(Err(e)) => {
+                        log::error!("could not load per-job wordlist: {}", e);
+                        continue;
+                    }
+                    Err(e) => {
+                        log::error!("per-job wordlist load panicked: {}", e);
+                        continue;
+                    }
+                }
+            }
+            None => words.clone(),
+        };
+
+        let (tx_term, mut rx_term) = mpsc::unbounded_channel::<FeroxResponse>();
+        let (tx_file, mut rx_file) = mpsc::unbounded_channel::<String>();
+
+        // the file transmitter exists only to satisfy scan_url; drain it to nowhere since server
+        // clients consume their results over the socket
+        tokio::spawn(async move { while rx_file.recv().await.is_some() {} });
+
+        let target = job.target.clone();
+        let scan_task = tokio::spawn(async move {
+            let base_depth = get_current_depth(&target);
+            scan_url(&target, words, base_depth, tx_term, tx_file).await;
+        });
+
+        // stream each FeroxResponse back to the client as newline-delimited JSON as it's found
+        while let Some(response) = rx_term.recv().await {
+            let mut line = serde_json::to_string(&response)?;
+            line.push('\n');
+            write_half.write_all(line.as_bytes()).await?;
+            write_half.flush().await?;
+        }
+
+        let _ = scan_task.await;
+    }
+
+    log::trace!("exit: handle_client");
+    Ok(())
+}
+
+/// Number of concurrent `--exec` hook children allowed when no explicit limit is configured
+const DEFAULT_EXEC_LIMIT: usize = 50;
+
+/// Spawn the per-finding command-hook consumer
+///
+/// Drains `rx` of every [`FeroxResponse`] the reporter emits and runs the configured `--exec`
+/// command against each one. A bounded [`Semaphore`] caps how many children run at once so a
+/// flood of findings can't fork-bomb the host. The returned handle completes once `rx` closes
+/// and every in-flight child has been reaped.
+fn spawn_exec_hooks(mut rx: UnboundedReceiver<FeroxResponse>) -> tokio::task::JoinHandle<()> {
+    log::trace!("enter: spawn_exec_hooks(<receiver>)");
+
+    let limit = if CONFIGURATION.exec_limit > 0 {
+        CONFIGURATION.exec_limit
+    } else {
+        DEFAULT_EXEC_LIMIT
+    };
+    let semaphore = Arc::new(Semaphore::new(limit));
+
+    let handle = tokio::spawn(async move {
+        while let Some(response) = rx.recv().await {
+            // block here once `limit` children are in flight; releases as they exit
+            let permit = match semaphore.clone().acquire_owned().await {
+                Ok(p) => p,
+                Err(_) => break,
+            };
+
+            tokio::spawn(async move {
+                exec_hook(&response).await;
+                // holding the permit until the child is reaped keeps the bound honest
+                drop(permit);
+            });
+        }
+
+        // rx closed; taking every permit waits until all in-flight children have been reaped
+        let _ = semaphore.acquire_many(limit as u32).await;
+    });
+
+    log::trace!("exit: spawn_exec_hooks");
+    handle
+}
+
+/// Run the configured `--exec` command against a single [`FeroxResponse`]
+///
+/// Placeholders like `{url}` and `{status}` are substituted into the argv, and the full response
+/// is written to the child's stdin as JSON. No shell is involved, so operators control the exact
+/// argv. The child's exit status is captured into the logs.
+async fn exec_hook(response: &FeroxResponse) {
+    let template = &CONFIGURATION.exec;
+    if template.is_empty() {
+        return;
+    }
+
+    // tokenize the template on whitespace first, then substitute within each argument, so a
+    // substituted url that contains a space can never re-tokenize into extra argv entries
+    let url = response.url().as_str();
+    let status = response.status().as_u16().to_string();
+    let argv: Vec<String> = template
+        .split_whitespace()
+        .map(|part| part.replace("{url}", url).replace("{status}", &status))
+        .collect();
+
+    let (program, args) = match argv.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+
+    let payload = match serde_json::to_vec(response) {
+        Ok(p) => p,
+        Err(e) => {
+            log::error!("could not serialize response for --exec: {}", e);
+            return;
+        }
+    };
+
+    // discard the child's stdout/stderr so a chatty hook tool can't scribble over the
+    // PROGRESS_PRINTER/MultiProgress display the rest of the tool routes around
+    let mut child = match Command::new(program)
+        .args(args)
+        .stdin(process::Stdio::piped())
+        .stdout(process::Stdio::null())
+        .stderr(process::Stdio::null())
+        .spawn()
+    {
+        Ok(c) => c,
+        Err(e) => {
+            log::error!("could not spawn --exec child {:?}: {}", program, e);
+            return;
+        }
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        if let Err(e) = stdin.write_all(&payload).await {
+            log::warn!("could not write response to --exec child's stdin: {}", e);
+        }
+        // dropping stdin here closes the pipe and signals EOF to the child
+    }
+
+    match child.wait().await {
+        Ok(status) => log::info!("--exec child for {} exited with {}", response.url(), status),
+        Err(e) => log::error!("error awaiting --exec child: {}", e),
+    }
+}
+
+/// Produce scan targets, streaming each one through an unbounded channel as it becomes available
+///
+/// A single command-line target yields exactly one item; when reading from stdin each line is
+/// forwarded the instant `LinesCodec` decodes it, so a feed like `tail -f hosts.txt | feroxbuster
+/// --stdin` begins scanning freshly appended targets immediately instead of waiting for the
+/// producer to close.
+fn get_targets() -> UnboundedReceiver<String> {
     log::trace!("enter: get_targets");
 
-    let mut targets = vec![];
+    let (tx, rx) = mpsc::unbounded_channel();
 
     if CONFIGURATION.stdin {
         // got targets from stdin, i.e. cat sites | ./feroxbuster ...
-        // just need to read the targets from stdin and spawn a future for each target found
-        let stdin = io::stdin(); // tokio's stdin, not std
-        let mut reader = FramedRead::new(stdin, LinesCodec::new());
-
-        while let Some(line) = reader.next().await {
-            targets.push(line?);
-        }
+        // read targets line-by-line and forward each one the moment it's decoded
+        tokio::spawn(async move {
+            let stdin = io::stdin(); // tokio's stdin, not std
+            let mut reader = FramedRead::new(stdin, LinesCodec::new());
+
+            while let Some(line) = reader.next().await {
+                match line {
+                    Ok(target) => {
+                        // the receiver hung up; no point reading any more
+                        if tx.send(target).is_err() {
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        log::error!("could not decode target from stdin: {}", e);
+                        break;
+                    }
+                }
+            }
+        });
     } else {
-        targets.push(CONFIGURATION.target_url.clone());
+        // a single command-line target; send it and let the channel close
+        let _ = tx.send(CONFIGURATION.target_url.clone());
     }
 
-    log::trace!("exit: get_targets -> {:?}", targets);
+    log::trace!("exit: get_targets -> <receiver>");
 
-    Ok(targets)
+    rx
 }
 
 #[tokio::main]
@@ -174,46 +656,69 @@ async fn main() {
     log::trace!("enter: main");
     log::debug!("{:#?}", *CONFIGURATION);
 
-    // spawn a thread that listens for keyboard input on stdin, when a user presses enter
-    // the input handler will toggle PAUSE_SCAN, which in turn is used to pause and resume
-    // scans that are already running
+    if CONFIGURATION.server {
+        // daemon mode: load the wordlist once and serve scan jobs over a socket, reusing the
+        // single wordlist Arc across every client and every job without its own wordlist override,
+        // rather than running a one-shot scan
+        let words = match get_unique_words_from_wordlist(&CONFIGURATION.wordlist) {
+            Ok(w) => w,
+            Err(e) => {
+                log::error!("could not load wordlist for server mode: {}", e);
+                process::exit(1);
+            }
+        };
+
+        if let Err(e) = run_server(words).await {
+            log::error!("server error: {}", e);
+            process::exit(1);
+        }
+
+        return;
+    }
+
+    // seed the runtime-adjustable knobs from the configured values so the control panel starts
+    // from the same settings the scan was launched with
+    SCAN_LIMIT.store(CONFIGURATION.threads, Ordering::Relaxed);
+    RUNTIME_VERBOSITY.store(CONFIGURATION.verbosity as usize, Ordering::Relaxed);
+
+    // spawn a thread that listens for keyboard input on stdin; the input handler acts as a live
+    // control panel, steering the running scan (pause/resume, cancel, concurrency, verbosity)
+    // through the atomics above
     tokio::task::spawn_blocking(terminal_input_handler);
 
     let save_output = !CONFIGURATION.output.is_empty(); // was -o used?
 
+    // stand up the per-finding command-hook consumer only when --exec was used; the reporter tees
+    // each FeroxResponse into this channel from the same point it feeds the terminal/file
+    // transmitters. When hooks are off we hand the reporter `None` so it never feeds an undrained
+    // receiver (which would otherwise grow without bound for the life of the scan).
+    let (tx_exec, exec_handle) = if CONFIGURATION.exec.is_empty() {
+        (None, None)
+    } else {
+        let (tx, rx) = mpsc::unbounded_channel::<FeroxResponse>();
+        (Some(tx), Some(spawn_exec_hooks(rx)))
+    };
+
     let (tx_term, tx_file, term_handle, file_handle) =
-        reporter::initialize(&CONFIGURATION.output, save_output);
+        reporter::initialize(&CONFIGURATION.output, save_output, tx_exec.clone());
 
-    // get targets from command line or stdin
-    let targets = match get_targets().await {
-        Ok(t) => t,
-        Err(e) => {
-            // should only happen in the event that there was an error reading from stdin
-            log::error!("{}", e);
-            ferox_print(
-                &format!(
-                    "{} {} {}",
-                    status_colorizer("ERROR"),
-                    module_colorizer("main::get_targets"),
-                    e
-                ),
-                &PROGRESS_PRINTER,
-            );
-            process::exit(1);
-        }
-    };
+    // get a stream of targets from the command line or stdin
+    let targets = get_targets();
 
     if !CONFIGURATION.quiet {
-        // only print banner if -q isn't used
+        // only print banner if -q isn't used; stdin targets stream in later, so we can only
+        // show the target known before the scan begins (the command-line url, if any)
+        let known = if CONFIGURATION.stdin {
+            vec![]
+        } else {
+            vec![CONFIGURATION.target_url.clone()]
+        };
         let std_stderr = stderr(); // std::io::stderr
-        banner::initialize(&targets, &CONFIGURATION, &VERSION, std_stderr).await;
+        banner::initialize(&known, &CONFIGURATION, &VERSION, std_stderr).await;
     }
 
-    // discard non-responsive targets
-    let live_targets = heuristics::connectivity_test(&targets).await;
-
-    // kick off a scan against any targets determined to be responsive
-    match scan(live_targets, tx_term.clone(), tx_file.clone()).await {
+    // kick off a scan against any targets determined to be responsive as they arrive
+    match scan(targets, tx_term.clone(), tx_file.clone()).await {
         Ok(_) => {
             log::info!("All scans complete!");
         }
@@ -252,6 +757,16 @@ async fn main() {
         log::trace!("done awaiting file output handler's receiver");
     }
 
+    // drop the command-hook transmitter and wait for any in-flight children to be reaped
+    drop(tx_exec);
+    if let Some(handle) = exec_handle {
+        log::trace!("awaiting command-hook consumer");
+        if let Err(e) = handle.await {
+            log::error!("error awaiting command-hook consumer: {}", e);
+        }
+        log::trace!("done awaiting command-hook consumer");
+    }
+
     // mark all scans complete so the terminal input handler will exit cleanly
     SCAN_COMPLETE.store(true, Ordering::Relaxed);
 